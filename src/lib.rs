@@ -19,17 +19,18 @@
 //! // Append a value to the end; it becomes the last value.
 //! l.append('🚀');
 //! assert_eq!(4, l.len());
-//! assert_eq!('🚀', l.last().unwrap().value());
+//! assert_eq!('🚀', *l.last().unwrap().value());
 //!
 //! // Prepend a value to the beginning; it becomes the first value.
 //! l.prepend('🛑');
 //! assert_eq!(5, l.len());
-//! assert_eq!('🛑', l.first().unwrap().value());
+//! assert_eq!('🛑', *l.first().unwrap().value());
 //! ```
 
-use std::cell::RefCell;
+use std::cell::{Ref, RefCell};
 use std::rc::{Rc, Weak};
 
+pub mod lru;
 pub mod prelude;
 
 /// Initialize a [DoublyLinkedList] with 0 or more items.
@@ -55,13 +56,7 @@ macro_rules! dll {
         DoublyLinkedList::new()
     };
     [$($ex: expr),+] => {
-        {
-            let mut l = DoublyLinkedList::new();
-            $(
-                l.append($ex);
-            )+
-            l
-        }
+        [$($ex),+].into_iter().collect::<DoublyLinkedList<_>>()
     };
 }
 
@@ -77,12 +72,18 @@ macro_rules! dll {
 pub struct DoublyLinkedList<T> {
     first: RefCell<Option<Rc<InternalNode<T>>>>,
     last: RefCell<Option<Weak<InternalNode<T>>>>,
+    len: usize,
 }
 
 /// Owns its next pointer.
+///
+/// `data` is wrapped in a `RefCell<Option<_>>` so that removal can `take()` the
+/// value out from under the node without requiring the node's `Rc` to be
+/// uniquely owned — a [NodeView] elsewhere in the program may still be holding
+/// a strong reference to the very node being removed.
 #[derive(Debug)]
 struct InternalNode<T> {
-    data: T,
+    data: RefCell<Option<T>>,
     next: RefCell<Option<Rc<InternalNode<T>>>>,
     prev: RefCell<Option<Weak<InternalNode<T>>>>,
 }
@@ -95,15 +96,13 @@ pub struct NodeView<T> {
     node: Rc<InternalNode<T>>,
 }
 
-impl<T> DoublyLinkedList<T>
-where
-    T: Copy,
-{
+impl<T> DoublyLinkedList<T> {
     /// Create an empty [DoublyLinkedList].
     pub fn new() -> Self {
         DoublyLinkedList {
             first: RefCell::new(None),
             last: RefCell::new(None),
+            len: 0,
         }
     }
 
@@ -123,10 +122,12 @@ where
 
     /// Returns how many elements are in the list.
     pub fn len(&self) -> usize {
-        match *self.first.borrow() {
-            None => 0,
-            Some(ref node) => node.len_acc(1),
-        }
+        self.len
+    }
+
+    /// Returns `true` if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
     }
 
     /// Append a value to the end of the list.
@@ -149,17 +150,266 @@ where
         }
     }
 
+    /// Returns a forward iterator over the values in the list, starting from [first](DoublyLinkedList::first).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dll::{dll, DoublyLinkedList};
+    /// let l = dll![1, 2, 3];
+    /// let values: Vec<_> = l.iter().collect();
+    /// assert_eq!(vec![1, 2, 3], values);
+    /// ```
+    pub fn iter(&self) -> Iter<T> {
+        Iter {
+            current: self.first(),
+        }
+    }
+
+    /// Returns a reverse iterator over the values in the list, starting from [last](DoublyLinkedList::last).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dll::{dll, DoublyLinkedList};
+    /// let l = dll![1, 2, 3];
+    /// let values: Vec<_> = l.iter_rev().collect();
+    /// assert_eq!(vec![3, 2, 1], values);
+    /// ```
+    pub fn iter_rev(&self) -> IterRev<T> {
+        IterRev {
+            current: self.last(),
+        }
+    }
+
+    /// Remove and return the first value in the list, or `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dll::{dll, DoublyLinkedList};
+    /// let mut l = dll![1, 2, 3];
+    /// assert_eq!(Some(1), l.pop_front());
+    /// assert_eq!(2, l.len());
+    /// ```
+    pub fn pop_front(&mut self) -> Option<T> {
+        let first = self.first.borrow_mut().take()?;
+        let next = first.next.borrow_mut().take();
+
+        match &next {
+            Some(next_node) => {
+                *next_node.prev.borrow_mut() = None;
+            }
+            None => {
+                *self.last.borrow_mut() = None;
+            }
+        }
+        *self.first.borrow_mut() = next;
+        self.len -= 1;
+
+        let value = first
+            .data
+            .borrow_mut()
+            .take()
+            .expect("node data is only taken once, when it is popped or removed");
+        Some(value)
+    }
+
+    /// Remove and return the last value in the list, or `None` if the list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dll::{dll, DoublyLinkedList};
+    /// let mut l = dll![1, 2, 3];
+    /// assert_eq!(Some(3), l.pop_back());
+    /// assert_eq!(2, l.len());
+    /// ```
+    pub fn pop_back(&mut self) -> Option<T> {
+        let last = self
+            .last
+            .borrow_mut()
+            .take()
+            .and_then(|weak| weak.upgrade())?;
+        let prev = last
+            .prev
+            .borrow_mut()
+            .take()
+            .and_then(|weak| weak.upgrade());
+
+        match &prev {
+            Some(prev_node) => {
+                *prev_node.next.borrow_mut() = None;
+                *self.last.borrow_mut() = Some(Rc::downgrade(prev_node));
+            }
+            None => {
+                *self.first.borrow_mut() = None;
+            }
+        }
+        self.len -= 1;
+
+        let value = last
+            .data
+            .borrow_mut()
+            .take()
+            .expect("node data is only taken once, when it is popped or removed");
+        Some(value)
+    }
+
+    /// Remove a node from the list, splicing its neighbours together, and return its value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dll::{dll, DoublyLinkedList};
+    /// let mut l = dll![1, 2, 3];
+    /// let middle = l.first().unwrap().next().unwrap();
+    /// assert_eq!(2, l.remove(middle));
+    /// assert_eq!(2, l.len());
+    /// ```
+    pub fn remove(&mut self, view: NodeView<T>) -> T {
+        let node = view.node;
+        let prev = node.prev.borrow().as_ref().and_then(|weak| weak.upgrade());
+        let next = node.next.borrow().clone();
+
+        match (&prev, &next) {
+            (None, None) => {
+                *self.first.borrow_mut() = None;
+                *self.last.borrow_mut() = None;
+            }
+            (None, Some(next_node)) => {
+                *next_node.prev.borrow_mut() = None;
+                *self.first.borrow_mut() = Some(Rc::clone(next_node));
+            }
+            (Some(prev_node), None) => {
+                *prev_node.next.borrow_mut() = None;
+                *self.last.borrow_mut() = Some(Rc::downgrade(prev_node));
+            }
+            (Some(prev_node), Some(next_node)) => {
+                *prev_node.next.borrow_mut() = Some(Rc::clone(next_node));
+                *next_node.prev.borrow_mut() = Some(Rc::downgrade(prev_node));
+            }
+        }
+
+        *node.next.borrow_mut() = None;
+        *node.prev.borrow_mut() = None;
+        self.len -= 1;
+
+        let value = node
+            .data
+            .borrow_mut()
+            .take()
+            .expect("node data is only taken once, when it is popped or removed");
+        value
+    }
+
+    /// Reverse the list in place, so the first element becomes the last and vice versa.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dll::{dll, DoublyLinkedList};
+    /// let mut l = dll![1, 2, 3];
+    /// l.reverse();
+    /// assert_eq!(vec![3, 2, 1], l.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn reverse(&mut self) {
+        let old_first = self.first.borrow().clone();
+        let mut previous: Option<Rc<InternalNode<T>>> = None;
+        let mut current = old_first.clone();
+
+        while let Some(node) = current {
+            let next = node.next.borrow_mut().take();
+            *node.prev.borrow_mut() = next.as_ref().map(Rc::downgrade);
+            *node.next.borrow_mut() = previous.take();
+            current = next;
+            previous = Some(node);
+        }
+
+        *self.first.borrow_mut() = previous;
+        *self.last.borrow_mut() = old_first.as_ref().map(Rc::downgrade);
+    }
+
+    /// Insert a value immediately after `view`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dll::{dll, DoublyLinkedList};
+    /// let mut l = dll![1, 3];
+    /// let first = l.first().unwrap();
+    /// l.insert_after(&first, 2);
+    /// assert_eq!(vec![1, 2, 3], l.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn insert_after(&mut self, view: &NodeView<T>, data: T) {
+        let node = &view.node;
+        let next = node.next.borrow().clone();
+
+        let new_node = Rc::new(InternalNode {
+            data: RefCell::new(Some(data)),
+            prev: RefCell::new(Some(Rc::downgrade(node))),
+            next: RefCell::new(next.clone()),
+        });
+
+        match &next {
+            Some(next_node) => {
+                *next_node.prev.borrow_mut() = Some(Rc::downgrade(&new_node));
+            }
+            None => {
+                *self.last.borrow_mut() = Some(Rc::downgrade(&new_node));
+            }
+        }
+
+        *node.next.borrow_mut() = Some(Rc::clone(&new_node));
+        self.len += 1;
+    }
+
+    /// Insert a value immediately before `view`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dll::{dll, DoublyLinkedList};
+    /// let mut l = dll![1, 3];
+    /// let last = l.last().unwrap();
+    /// l.insert_before(&last, 2);
+    /// assert_eq!(vec![1, 2, 3], l.iter().collect::<Vec<_>>());
+    /// ```
+    pub fn insert_before(&mut self, view: &NodeView<T>, data: T) {
+        let node = &view.node;
+        let prev = node.prev.borrow().as_ref().and_then(|weak| weak.upgrade());
+
+        let new_node = Rc::new(InternalNode {
+            data: RefCell::new(Some(data)),
+            prev: RefCell::new(prev.as_ref().map(Rc::downgrade)),
+            next: RefCell::new(Some(Rc::clone(node))),
+        });
+
+        match &prev {
+            Some(prev_node) => {
+                *prev_node.next.borrow_mut() = Some(Rc::clone(&new_node));
+            }
+            None => {
+                *self.first.borrow_mut() = Some(Rc::clone(&new_node));
+            }
+        }
+
+        *node.prev.borrow_mut() = Some(Rc::downgrade(&new_node));
+        self.len += 1;
+    }
+
     // Private methods
 
     fn insert_first(&mut self, data: T) {
         let node = Rc::new(InternalNode {
-            data,
+            data: RefCell::new(Some(data)),
             prev: RefCell::new(None),
             next: RefCell::new(None),
         });
 
         *self.first.borrow_mut() = Some(Rc::clone(&node));
         *self.last.borrow_mut() = Some(Rc::downgrade(&node));
+        self.len += 1;
     }
 
     fn append_subsequent(&mut self, data: T) {
@@ -171,55 +421,44 @@ where
             .unwrap();
 
         let node = Rc::new(InternalNode {
-            data,
+            data: RefCell::new(Some(data)),
             prev: RefCell::new(Some(Rc::downgrade(&last))),
             next: RefCell::new(None),
         });
 
         *last.next.borrow_mut() = Some(Rc::clone(&node));
         *self.last.borrow_mut() = Some(Rc::downgrade(&node));
+        self.len += 1;
     }
 
     fn prepend_subsequent(&mut self, data: T) {
         let first = Rc::clone(self.first.borrow().as_ref().unwrap());
 
         let node = Rc::new(InternalNode {
-            data,
+            data: RefCell::new(Some(data)),
             prev: RefCell::new(None),
             next: RefCell::new(Some(Rc::clone(&first))),
         });
 
         *first.prev.borrow_mut() = Some(Rc::downgrade(&node));
         *self.first.borrow_mut() = Some(Rc::clone(&node));
+        self.len += 1;
     }
 }
 
-impl<T> InternalNode<T>
-where
-    T: Copy,
-{
-    // calculate length via tail-recursion and accumulator
-    fn len_acc(&self, acc: usize) -> usize {
-        match *self.next.borrow() {
-            None => acc,
-            Some(ref next) => next.len_acc(acc + 1),
-        }
-    }
-}
-
-impl<T> NodeView<T>
-where
-    T: Copy,
-{
+impl<T> NodeView<T> {
     fn new(source: &Rc<InternalNode<T>>) -> Self {
         NodeView {
             node: Rc::clone(source),
         }
     }
 
-    /// Return the value from this point in the list.
-    pub fn value(&self) -> T {
-        self.node.data
+    /// Return a reference to the value at this point in the list.
+    pub fn value(&self) -> Ref<'_, T> {
+        Ref::map(self.node.data.borrow(), |data| {
+            data.as_ref()
+                .expect("node data is only taken once, when it is popped or removed")
+        })
     }
 
     /// Return a [NodeView] of the next item in list, or `None` if this is the last item in the list.
@@ -242,6 +481,83 @@ where
     }
 }
 
+/// A forward iterator over the values of a [DoublyLinkedList], returned by [DoublyLinkedList::iter].
+///
+/// Yields clones of each value rather than references, since each value lives
+/// behind the list's own `Rc`-shared nodes for as long as the list exists.
+pub struct Iter<T> {
+    current: Option<NodeView<T>>,
+}
+
+impl<T> Iterator for Iter<T>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let current = self.current.take()?;
+        let value = (*current.value()).clone();
+        self.current = current.next();
+        Some(value)
+    }
+}
+
+/// A reverse iterator over the values of a [DoublyLinkedList], returned by [DoublyLinkedList::iter_rev].
+pub struct IterRev<T> {
+    current: Option<NodeView<T>>,
+}
+
+impl<T> Iterator for IterRev<T>
+where
+    T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let current = self.current.take()?;
+        let value = (*current.value()).clone();
+        self.current = current.prev();
+        Some(value)
+    }
+}
+
+/// A consuming iterator over the values of a [DoublyLinkedList], returned by its
+/// [IntoIterator] implementation.
+///
+/// Drains the list from the front via [DoublyLinkedList::pop_front], so it yields
+/// owned values without requiring `T: Clone`.
+pub struct IntoIter<T> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+}
+
+impl<T> IntoIterator for DoublyLinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<T> FromIterator<T> for DoublyLinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = DoublyLinkedList::new();
+        for item in iter {
+            list.append(item);
+        }
+        list
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::DoublyLinkedList;
@@ -281,10 +597,10 @@ mod tests {
         l.append('a');
 
         let a = l.first().unwrap();
-        assert_eq!('a', a.value());
+        assert_eq!('a', *a.value());
 
         let b = l.last().unwrap();
-        assert_eq!('a', b.value());
+        assert_eq!('a', *b.value());
     }
 
     #[test]
@@ -297,8 +613,8 @@ mod tests {
 
         let first = l.first().unwrap();
         let last = l.last().unwrap();
-        assert_eq!('a', first.value());
-        assert_eq!('b', last.value());
+        assert_eq!('a', *first.value());
+        assert_eq!('b', *last.value());
     }
 
     #[test]
@@ -309,7 +625,7 @@ mod tests {
 
         let first = l.first().unwrap();
         let last = first.next().unwrap();
-        assert_eq!('b', last.value());
+        assert_eq!('b', *last.value());
     }
 
     #[test]
@@ -320,7 +636,258 @@ mod tests {
 
         let last = l.last().unwrap();
         let first = last.prev().unwrap();
-        assert_eq!('a', first.value());
+        assert_eq!('a', *first.value());
+    }
+
+    #[test]
+    fn empty_list_is_empty() {
+        let l: DoublyLinkedList<i32> = DoublyLinkedList::new();
+        assert!(l.is_empty());
+    }
+
+    #[test]
+    fn nonempty_list_is_not_empty() {
+        let mut l = DoublyLinkedList::new();
+        l.append('a');
+        assert!(!l.is_empty());
+    }
+
+    #[test]
+    fn can_remove_the_sole_element() {
+        let mut l = DoublyLinkedList::new();
+        l.append('a');
+
+        let a = l.first().unwrap();
+        assert_eq!('a', l.remove(a));
+        assert_eq!(0, l.len());
+        assert!(l.first().is_none());
+        assert!(l.last().is_none());
+    }
+
+    #[test]
+    fn can_remove_the_head() {
+        let mut l = dll!['a', 'b', 'c'];
+
+        let a = l.first().unwrap();
+        assert_eq!('a', l.remove(a));
+        assert_eq!(2, l.len());
+        assert_eq!('b', *l.first().unwrap().value());
+    }
+
+    #[test]
+    fn can_remove_the_tail() {
+        let mut l = dll!['a', 'b', 'c'];
+
+        let c = l.last().unwrap();
+        assert_eq!('c', l.remove(c));
+        assert_eq!(2, l.len());
+        assert_eq!('b', *l.last().unwrap().value());
+    }
+
+    #[test]
+    fn can_remove_an_interior_node() {
+        let mut l = dll!['a', 'b', 'c'];
+
+        let b = l.first().unwrap().next().unwrap();
+        assert_eq!('b', l.remove(b));
+        assert_eq!(2, l.len());
+
+        let first = l.first().unwrap();
+        assert_eq!('a', *first.value());
+        assert_eq!('c', *first.next().unwrap().value());
+    }
+
+    #[test]
+    fn can_remove_a_node_while_another_view_of_it_is_alive() {
+        let mut l = dll!['a', 'b', 'c'];
+
+        let a = l.first().unwrap();
+        let also_a = l.first().unwrap();
+        assert_eq!('a', l.remove(also_a));
+        assert_eq!(2, l.len());
+        assert_eq!('b', *l.first().unwrap().value());
+        drop(a);
+    }
+
+    #[test]
+    fn pop_front_returns_values_in_order() {
+        let mut l = dll!['a', 'b', 'c'];
+
+        assert_eq!(Some('a'), l.pop_front());
+        assert_eq!(Some('b'), l.pop_front());
+        assert_eq!(Some('c'), l.pop_front());
+        assert_eq!(None, l.pop_front());
+        assert_eq!(0, l.len());
+    }
+
+    #[test]
+    fn pop_back_returns_values_in_order() {
+        let mut l = dll!['a', 'b', 'c'];
+
+        assert_eq!(Some('c'), l.pop_back());
+        assert_eq!(Some('b'), l.pop_back());
+        assert_eq!(Some('a'), l.pop_back());
+        assert_eq!(None, l.pop_back());
+        assert_eq!(0, l.len());
+    }
+
+    #[test]
+    fn pop_front_does_not_panic_while_another_view_is_alive() {
+        let mut l = dll!['a', 'b', 'c'];
+
+        let a = l.first().unwrap();
+        assert_eq!(Some('a'), l.pop_front());
+        assert_eq!(2, l.len());
+        assert_eq!('b', *l.first().unwrap().value());
+        drop(a);
+    }
+
+    #[test]
+    fn pop_back_does_not_panic_while_another_view_is_alive() {
+        let mut l = dll!['a', 'b', 'c'];
+
+        let c = l.last().unwrap();
+        assert_eq!(Some('c'), l.pop_back());
+        assert_eq!(2, l.len());
+        assert_eq!('b', *l.last().unwrap().value());
+        drop(c);
+    }
+
+    #[test]
+    fn pop_front_then_append_still_works() {
+        let mut l = dll!['a', 'b'];
+        assert_eq!(Some('a'), l.pop_front());
+
+        l.append('c');
+        assert_eq!(2, l.len());
+        assert_eq!('b', *l.first().unwrap().value());
+        assert_eq!('c', *l.last().unwrap().value());
+    }
+
+    #[test]
+    fn can_reverse_a_list() {
+        let mut l = dll!['a', 'b', 'c'];
+        l.reverse();
+
+        assert_eq!(3, l.len());
+        assert_eq!(vec!['c', 'b', 'a'], l.iter().collect::<Vec<_>>());
+        assert_eq!('c', *l.first().unwrap().value());
+        assert_eq!('a', *l.last().unwrap().value());
+    }
+
+    #[test]
+    fn can_reverse_an_empty_list() {
+        let mut l: DoublyLinkedList<i32> = dll![];
+        l.reverse();
+        assert_eq!(0, l.len());
+    }
+
+    #[test]
+    fn reverse_does_not_panic_while_a_view_is_alive_and_keeps_it_valid() {
+        let mut l = dll!['a', 'b', 'c'];
+        let b = l.first().unwrap().next().unwrap();
+
+        l.reverse();
+
+        assert_eq!(vec!['c', 'b', 'a'], l.iter().collect::<Vec<_>>());
+        assert_eq!('b', *b.value());
+        assert_eq!('a', *b.next().unwrap().value());
+        assert_eq!('c', *b.prev().unwrap().value());
+    }
+
+    #[test]
+    fn can_insert_after_the_middle() {
+        let mut l = dll!['a', 'c', 'd'];
+        let a = l.first().unwrap();
+        l.insert_after(&a, 'b');
+
+        assert_eq!(4, l.len());
+        assert_eq!(vec!['a', 'b', 'c', 'd'], l.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn can_insert_after_the_tail() {
+        let mut l = dll!['a', 'b'];
+        let b = l.last().unwrap();
+        l.insert_after(&b, 'c');
+
+        assert_eq!(3, l.len());
+        assert_eq!('c', *l.last().unwrap().value());
+        assert_eq!(vec!['a', 'b', 'c'], l.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn can_insert_before_the_middle() {
+        let mut l = dll!['a', 'c', 'd'];
+        let c = l.first().unwrap().next().unwrap();
+        l.insert_before(&c, 'b');
+
+        assert_eq!(4, l.len());
+        assert_eq!(vec!['a', 'b', 'c', 'd'], l.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn can_insert_before_the_head() {
+        let mut l = dll!['b', 'c'];
+        let b = l.first().unwrap();
+        l.insert_before(&b, 'a');
+
+        assert_eq!(3, l.len());
+        assert_eq!('a', *l.first().unwrap().value());
+        assert_eq!(vec!['a', 'b', 'c'], l.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn can_iterate_forward() {
+        let l = dll!['a', 'b', 'c'];
+        let values: Vec<_> = l.iter().collect();
+        assert_eq!(vec!['a', 'b', 'c'], values);
+    }
+
+    #[test]
+    fn can_iterate_backward() {
+        let l = dll!['a', 'b', 'c'];
+        let values: Vec<_> = l.iter_rev().collect();
+        assert_eq!(vec!['c', 'b', 'a'], values);
+    }
+
+    #[test]
+    fn can_into_iter() {
+        let l = dll!['a', 'b', 'c'];
+        let values: Vec<_> = l.into_iter().collect();
+        assert_eq!(vec!['a', 'b', 'c'], values);
+    }
+
+    /// Deliberately not `Clone`, to prove `into_iter` doesn't need it.
+    struct NotClone(i32);
+
+    #[test]
+    fn into_iter_works_for_non_clone_values() {
+        let mut l = DoublyLinkedList::new();
+        l.append(NotClone(1));
+        l.append(NotClone(2));
+        l.append(NotClone(3));
+
+        let values: Vec<i32> = l.into_iter().map(|v| v.0).collect();
+        assert_eq!(vec![1, 2, 3], values);
+    }
+
+    #[test]
+    fn can_collect_from_iterator() {
+        let l: DoublyLinkedList<i32> = (1..=3).collect();
+        assert_eq!(3, l.len());
+        assert_eq!(1, *l.first().unwrap().value());
+        assert_eq!(3, *l.last().unwrap().value());
+    }
+
+    #[test]
+    fn can_hold_non_copy_values() {
+        let mut l = DoublyLinkedList::new();
+        l.append(String::from("hello"));
+        l.append(String::from("world"));
+
+        assert_eq!("hello", *l.first().unwrap().value());
+        assert_eq!("world", *l.last().unwrap().value());
     }
 
     #[test]
@@ -333,7 +900,7 @@ mod tests {
     fn can_use_macro_with_mulitple_values() {
         let l = dll!['a', 'b', 'c', 'x', 'y', 'z'];
         assert_eq!(6, l.len());
-        assert_eq!('a', l.first().unwrap().value());
-        assert_eq!('z', l.last().unwrap().value());
+        assert_eq!('a', *l.first().unwrap().value());
+        assert_eq!('z', *l.last().unwrap().value());
     }
 }
@@ -0,0 +1,169 @@
+//! A fixed-capacity least-recently-used cache, built on top of [DoublyLinkedList](crate::DoublyLinkedList).
+//!
+//! The list keeps entries ordered from most- to least-recently-used; a side
+//! [HashMap] maps keys to their [NodeView] so that touching an entry can
+//! splice it back to the front in O(1) instead of walking the list.
+
+use std::cell::Ref;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{DoublyLinkedList, NodeView};
+
+/// A fixed-capacity cache that evicts the least-recently-used entry when full.
+///
+/// # Examples
+///
+/// ```
+/// use dll::lru::LruCache;
+///
+/// let mut cache = LruCache::new(2);
+/// cache.put(1, "a");
+/// cache.put(2, "b");
+/// assert_eq!("a", *cache.get(&1).unwrap());
+///
+/// // 2 is now the least-recently-used entry, so it gets evicted.
+/// cache.put(3, "c");
+/// assert!(cache.get(&2).is_none());
+/// assert_eq!("c", *cache.get(&3).unwrap());
+/// ```
+pub struct LruCache<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    capacity: usize,
+    list: DoublyLinkedList<(K, V)>,
+    index: HashMap<K, NodeView<(K, V)>>,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Create an empty cache that holds at most `capacity` entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "LruCache capacity must be greater than 0");
+        LruCache {
+            capacity,
+            list: DoublyLinkedList::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    /// Returns how many entries are currently in the cache.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns `true` if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Look up `key`, moving it to the front of the list as most-recently-used.
+    ///
+    /// Returns `None` if `key` is not present.
+    pub fn get(&mut self, key: &K) -> Option<Ref<'_, V>> {
+        self.touch(key)?;
+        let view = self.index.get(key)?;
+        Some(Ref::map(view.value(), |pair| &pair.1))
+    }
+
+    /// Insert or update `key` with `value`, moving it to the front of the list.
+    ///
+    /// If the cache is over capacity afterwards, the least-recently-used entry is evicted.
+    pub fn put(&mut self, key: K, value: V) {
+        if let Some(view) = self.index.remove(&key) {
+            self.list.remove(view);
+        }
+
+        self.list.prepend((key.clone(), value));
+        let view = self.list.first().expect("list is non-empty after prepend");
+        self.index.insert(key, view);
+
+        if self.list.len() > self.capacity {
+            // Drop the index's reference to the tail node *before* popping it, so the
+            // list holds the only remaining strong reference and can reclaim it.
+            if let Some(evicted_key) = self.list.last().map(|view| view.value().0.clone()) {
+                self.index.remove(&evicted_key);
+            }
+            self.list.pop_back();
+        }
+    }
+
+    /// Move the node for `key` to the front of the list, returning `Some(())` if it existed.
+    fn touch(&mut self, key: &K) -> Option<()> {
+        let view = self.index.remove(key)?;
+        let (k, v) = self.list.remove(view);
+        self.list.prepend((k, v));
+        let new_view = self.list.first().expect("list is non-empty after prepend");
+        self.index.insert(key.clone(), new_view);
+        Some(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LruCache;
+
+    #[test]
+    fn new_cache_is_empty() {
+        let cache: LruCache<i32, i32> = LruCache::new(2);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn can_get_a_value_that_was_put() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, "a");
+        assert_eq!("a", *cache.get(&1).unwrap());
+    }
+
+    #[test]
+    fn missing_key_returns_none() {
+        let mut cache: LruCache<i32, &str> = LruCache::new(2);
+        assert!(cache.get(&1).is_none());
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_entry_when_over_capacity() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, "a");
+        cache.put(2, "b");
+        cache.put(3, "c");
+
+        assert_eq!(2, cache.len());
+        assert!(cache.get(&1).is_none());
+        assert_eq!("b", *cache.get(&2).unwrap());
+        assert_eq!("c", *cache.get(&3).unwrap());
+    }
+
+    #[test]
+    fn getting_an_entry_protects_it_from_eviction() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, "a");
+        cache.put(2, "b");
+
+        // Touch 1, making 2 the least-recently-used entry.
+        assert_eq!("a", *cache.get(&1).unwrap());
+
+        cache.put(3, "c");
+        assert_eq!("a", *cache.get(&1).unwrap());
+        assert!(cache.get(&2).is_none());
+        assert_eq!("c", *cache.get(&3).unwrap());
+    }
+
+    #[test]
+    fn putting_an_existing_key_updates_its_value() {
+        let mut cache = LruCache::new(2);
+        cache.put(1, "a");
+        cache.put(1, "z");
+
+        assert_eq!(1, cache.len());
+        assert_eq!("z", *cache.get(&1).unwrap());
+    }
+}